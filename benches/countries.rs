@@ -0,0 +1,33 @@
+use axum::{body::Body, http::Request};
+use criterion::{Criterion, criterion_group, criterion_main};
+use tower::ServiceExt;
+
+const DATASET: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.json");
+
+fn bench_requests(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let router = runtime.block_on(wanderling_service::app(DATASET)).unwrap();
+
+    for (name, uri) in [
+        ("list_full_page", "/api/countries?items_per_page=100"),
+        (
+            "list_filtered",
+            "/api/countries?filter_name=a&items_per_page=100",
+        ),
+        ("get_by_id", "/api/countries/5"),
+        ("nearest", "/api/countries/nearest?lat=50&lon=14&limit=10"),
+    ] {
+        c.bench_function(name, |b| {
+            b.to_async(&runtime).iter(|| async {
+                router
+                    .clone()
+                    .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+                    .await
+                    .unwrap()
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench_requests);
+criterion_main!(benches);
@@ -0,0 +1,11 @@
+fn main() {
+    #[cfg(feature = "grpc")]
+    {
+        // Uses the bundled protoc, so building needs none installed
+        let mut config = tonic_prost_build::Config::new();
+        config.protoc_executable(protoc_bin_vendored::protoc_bin_path().unwrap());
+        tonic_prost_build::configure()
+            .compile_with_config(config, &["proto/wanderling.proto"], &["proto"])
+            .unwrap();
+    }
+}
@@ -1,7 +1,18 @@
 use anyhow::Result;
-use wanderling_service::run;
+use clap::Parser;
+use wanderling_service::{Command, Config, init_tracing, run, validate_dataset_file};
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    Ok(run().await?)
+    let config = Config::parse();
+    if let Some(Command::Validate { dataset }) = &config.command {
+        let report = validate_dataset_file(dataset).await;
+        print!("{}: {report}", dataset.display());
+        if !report.is_ok() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    init_tracing(&config.log_level);
+    run(config).await
 }